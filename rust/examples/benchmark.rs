@@ -1,5 +1,5 @@
+use indexmap::IndexMap;
 use serde::Deserialize;
-use std::collections::HashMap;
 use std::error::Error;
 use std::time::Instant;
 
@@ -18,7 +18,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // 1. Read and Process the CSV data
     println!("Reading and processing data.csv...");
-    let mut sets_map: HashMap<i32, Vec<i32>> = HashMap::new();
+    let mut sets_map: IndexMap<i32, Vec<i32>> = IndexMap::new();
     let mut rdr = csv::Reader::from_path("data.csv")?;
 
     for result in rdr.deserialize() {
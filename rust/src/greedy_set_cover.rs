@@ -1,80 +1,100 @@
 use bitvec::prelude::*;
-use std::collections::{HashMap, HashSet};
-use std::hash::Hash;
+use indexmap::IndexMap;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
 
 /// Finds an approximate solution to the set cover problem using a greedy algorithm.
 ///
+/// A thin wrapper over [`greedy_cover`]: each set is adapted to [`Coverable`] via
+/// [`VecSetItem`], so the "pick the set covering the most new elements, remove its
+/// elements from every other candidate" loop lives in one place rather than being
+/// hand-rolled here too. `sets` is an `IndexMap` rather than a `HashMap` so that its
+/// iteration order matches insertion order: when two sets cover the same number of
+/// elements, the one inserted first wins, making the returned cover reproducible
+/// across runs for identical input.
+///
 /// # Arguments
 ///
-/// * `sets`: A `HashMap` where keys are the identifiers of the sets and values are vectors
+/// * `sets`: An `IndexMap` where keys are the identifiers of the sets and values are vectors
 ///   of the elements in each set.
 ///
 /// # Type Parameters
 ///
-/// * `K`: The type of the set identifiers (keys in the HashMap). Must be cloneable, hashable,
+/// * `K`: The type of the set identifiers (keys in the IndexMap). Must be cloneable, hashable,
 ///   and equatable.
 /// * `T`: The type of the elements within the sets. Must be cloneable, hashable, and equatable.
+/// * `S`: The `BuildHasher` used by `sets` and the coverage check. Defaults to the standard
+///   library's `RandomState`; pass a faster hasher (e.g. `ahash`/`FxHasher`) for integer or
+///   other DoS-irrelevant element types to speed up the millions of lookups these algorithms
+///   perform.
 ///
 /// # Returns
 ///
-/// A `HashMap` containing the sets that form the cover.
+/// A `Vec` containing the keys of the sets that form the cover, in selection order.
 ///
 /// # Panics
 ///
 /// Panics if the input sets do not collectively cover all of their unique elements.
-pub fn greedy_set_cover_0<K, T>(sets: &HashMap<K, Vec<T>>) -> HashSet<K>
+pub fn greedy_set_cover_0<K, T, S>(sets: &IndexMap<K, Vec<T>, S>) -> Vec<K>
 where
-    K: Clone + Hash + Eq + std::fmt::Debug, // Added Debug for error message
-    T: Clone + Hash + Eq + std::fmt::Debug, // Added Debug for error message
+    K: Clone + Hash + Eq + std::fmt::Debug,
+    T: Clone + Hash + Eq + std::fmt::Debug,
+    S: BuildHasher + Default,
 {
-    let mut uncovered_elements: HashSet<T> = sets.values().flatten().cloned().collect();
-    let mut cover = HashSet::new();
-
-    for _ in 0..sets.len() {
-        if uncovered_elements.is_empty() {
-            break;
-        }
-
-        let mut best_set_key: Option<K> = None;
-        let mut best_set_covered: HashSet<T> = HashSet::new();
-
-        // Iterate through all the provided sets to find the one that covers the most
-        // currently uncovered elements.
-        for (key, set_elements) in sets {
-            let covered_by_this_set: HashSet<T> = set_elements
-                .iter()
-                .filter(|element| uncovered_elements.contains(element))
-                .cloned()
-                .collect();
-
-            if covered_by_this_set.len() > best_set_covered.len() {
-                best_set_key = Some(key.clone());
-                best_set_covered = covered_by_this_set;
-            }
-        }
+    let items: Vec<VecSetItem<K, T>> = sets
+        .iter()
+        .map(|(key, elements)| VecSetItem {
+            key: key.clone(),
+            elements: elements.clone(),
+        })
+        .collect();
 
-        // If a best set was found, add it to the cover and remove its elements from the universe.
-        if let Some(key) = best_set_key {
-            uncovered_elements.retain(|e| !best_set_covered.contains(e));
-            cover.insert(key.clone());
-        } else if !uncovered_elements.is_empty() {
-            panic!(
-                "Error: Unable to find a set to cover the remaining elements: {:?}",
-                uncovered_elements
-            );
-        }
-    }
+    let cover: Vec<K> = greedy_cover(items, sets.len());
 
-    if !uncovered_elements.is_empty() {
+    let covered_universe: HashSet<T, S> = cover
+        .iter()
+        .flat_map(|key| sets.get(key).expect("cover only contains keys from sets").iter().cloned())
+        .collect();
+    if covered_universe.len() != make_universe(sets).len() {
         panic!(
-            "Error: Could not cover all elements after iterating through all sets. Remaining elements: {:?}",
-            uncovered_elements
+            "Error: Could not cover all elements after iterating through all sets. Cover so far: {:?}",
+            cover
         );
     }
 
     cover
 }
 
+/// Adapts a `(key, elements)` pair — the shape every `IndexMap<K, Vec<T>>` entry
+/// already has — to [`Coverable`], so [`greedy_set_cover_0`] can drive [`greedy_cover`]
+/// without first interning a `BitVec` universe.
+struct VecSetItem<K, T> {
+    key: K,
+    elements: Vec<T>,
+}
+
+impl<K: Clone, T: Clone + PartialEq> Coverable for VecSetItem<K, T> {
+    type Object = K;
+    type Set = Vec<T>;
+
+    fn covering_set(&self) -> &Vec<T> {
+        &self.elements
+    }
+
+    fn object(&self) -> K {
+        self.key.clone()
+    }
+
+    fn update_covering_set(&mut self, chosen: &Vec<T>) {
+        self.elements.retain(|element| !chosen.contains(element));
+    }
+
+    fn score(&self) -> usize {
+        self.elements.len()
+    }
+}
+
 /// Creates a mapping from unique elements to consecutive integers (0, 1, 2...).
 ///
 /// This function iterates through a collection of elements and assigns a unique `usize`
@@ -85,6 +105,7 @@ where
 /// * `T`: The type of the elements. It must be hashable and equatable to be used
 ///   as a key in the resulting `HashMap`, and cloneable to be owned by the map.
 /// * `I`: An iterator that yields references to elements of type `T`.
+/// * `S`: The `BuildHasher` of the resulting `HashMap`.
 ///
 /// # Arguments
 ///
@@ -94,12 +115,13 @@ where
 /// # Returns
 ///
 /// A `HashMap` where each key is a unique element and the value is its assigned integer ID.
-pub fn map_elements_to_integers<T, I>(elements: I) -> HashMap<T, usize>
+pub fn map_elements_to_integers<T, I, S>(elements: I) -> HashMap<T, usize, S>
 where
     T: Hash + Eq + Clone,
     I: IntoIterator<Item = T>,
+    S: BuildHasher + Default,
 {
-    let mut mapping = HashMap::new();
+    let mut mapping: HashMap<T, usize, S> = HashMap::default();
     let mut next_id = 0;
     for element in elements {
         // The `entry` API is efficient: it only performs one hash lookup.
@@ -122,6 +144,7 @@ where
 ///
 /// * `T`: The type of the elements, which must be cloneable to be used as a value
 ///   in the new map.
+/// * `S`: The `BuildHasher` shared by the input and returned maps.
 ///
 /// # Arguments
 ///
@@ -130,7 +153,11 @@ where
 /// # Returns
 ///
 /// A `HashMap` where each key is an integer ID and the value is the original element.
-pub fn revert_integer_mapping<T: Clone>(mapping: &HashMap<T, usize>) -> HashMap<usize, T> {
+pub fn revert_integer_mapping<T, S>(mapping: &HashMap<T, usize, S>) -> HashMap<usize, T, S>
+where
+    T: Clone,
+    S: BuildHasher + Default,
+{
     mapping
         .iter()
         .map(|(element, &id)| (id, element.clone()))
@@ -147,6 +174,7 @@ pub fn revert_integer_mapping<T: Clone>(mapping: &HashMap<T, usize>) -> HashMap<
 /// * `T`: The type of the elements. It must be hashable and equatable to be used
 ///   as a key in the resulting `HashMap`, and cloneable to be owned by the map.
 /// * `I`: An iterator that yields references to elements of type `T`.
+/// * `S`: The `BuildHasher` of the resulting `HashMap`.
 ///
 /// # Arguments
 ///
@@ -156,12 +184,13 @@ pub fn revert_integer_mapping<T: Clone>(mapping: &HashMap<T, usize>) -> HashMap<
 /// # Returns
 ///
 /// A `HashMap` where each key is a unique element and the value is its assigned integer ID.
-pub fn map_elements_to_integers_owned<T, I>(elements: I) -> HashMap<T, usize>
+pub fn map_elements_to_integers_owned<T, I, S>(elements: I) -> HashMap<T, usize, S>
 where
     T: Hash + Eq + Clone,
     I: IntoIterator<Item = T>,
+    S: BuildHasher + Default,
 {
-    let mut mapping = HashMap::new();
+    let mut mapping: HashMap<T, usize, S> = HashMap::default();
     let mut next_id = 0;
     for element in elements {
         mapping.entry(element).or_insert_with(|| {
@@ -177,72 +206,386 @@ where
 ///
 /// # Arguments
 ///
-/// * `sets`: A `HashMap` where keys are the identifiers of the sets and values are vectors
+/// * `sets`: An `IndexMap` where keys are the identifiers of the sets and values are vectors
 ///   of the elements in each set.
 ///
 /// # Type Parameters
 ///
-/// * `K`: The type of the set identifiers (keys in the HashMap).
+/// * `K`: The type of the set identifiers (keys in the IndexMap).
 /// * `T`: The type of the elements within the sets. Must be cloneable, hashable, and equatable.
+/// * `S`: The `BuildHasher` shared by `sets` and the returned universe.
 ///
 /// # Returns
 ///
 /// A `HashSet` containing all unique elements from the input sets.
-fn make_universe<K, T>(sets: &HashMap<K, Vec<T>>) -> HashSet<T>
+fn make_universe<K, T, S>(sets: &IndexMap<K, Vec<T>, S>) -> HashSet<T, S>
 where
     T: Clone + Hash + Eq,
+    S: BuildHasher + Default,
 {
     sets.values().flatten().cloned().collect()
 }
 
+/// Interns every element across `sets` into consecutive integer ids and builds each
+/// set's membership as a `BitVec` over those ids. Shared by every `BitVec`-based
+/// variant below so the "intern elements, then build one bitset per input set" setup
+/// isn't re-implemented per caller.
+///
+/// # Returns
+///
+/// Each set's key alongside its `BitVec`, in `sets`' insertion order, and the size of
+/// the interned universe.
+fn build_bit_sets<K, T, S>(sets: &IndexMap<K, Vec<T>, S>) -> (IndexMap<K, BitVec, S>, usize)
+where
+    K: Clone + Hash + Eq,
+    T: Clone + Hash + Eq,
+    S: BuildHasher + Default,
+{
+    let universe = make_universe(sets);
+    let mapping: HashMap<T, usize, S> = map_elements_to_integers_owned(universe);
+    let universe_size = mapping.len();
+
+    let mut bit_sets: IndexMap<K, BitVec, S> = IndexMap::default();
+    for (key, elements) in sets {
+        let mut bv = bitvec![0; universe_size];
+        for element in elements {
+            if let Some(&id) = mapping.get(element) {
+                bv.set(id, true);
+            }
+        }
+        bit_sets.insert(key.clone(), bv);
+    }
+
+    (bit_sets, universe_size)
+}
+
 /// Finds an approximate solution to the set cover problem using a greedy algorithm.
-/// Maps all elements to integer first, then leveraging set operation on integers
-/// This incurs cost at the beginning but is faster later, so if this better than
-/// algorithm 0 depends on the number of sets and elements and number of needed sets -
-/// so it will be hard to say in advance
+/// Maps all elements to integers first, then leverages bitset intersection instead of
+/// `HashSet` operations; whether that's faster than algorithm 0 depends on the number
+/// of sets, elements, and needed sets, so it's hard to say in advance.
+///
+/// Like [`greedy_set_cover_0`], a thin wrapper over [`greedy_cover`]: each set is
+/// interned into a `BitVec` over the shared universe (via [`build_bit_sets`]) and
+/// adapted to [`Coverable`] via [`BitVecSetItem`], so rounds run as bitset
+/// intersections instead of `VecSetItem`'s retain/contains scan.
+///
 /// # Arguments
 ///
-/// * `sets`: A `HashMap` where keys are the identifiers of the sets and values are vectors
+/// * `sets`: An `IndexMap` where keys are the identifiers of the sets and values are vectors
 ///   of the elements in each set.
 ///
 /// # Type Parameters
 ///
-/// * `K`: The type of the set identifiers (keys in the HashMap). Must be cloneable, hashable,
+/// * `K`: The type of the set identifiers (keys in the IndexMap). Must be cloneable, hashable,
 ///   and equatable.
 /// * `T`: The type of the elements within the sets. Must be cloneable, hashable, and equatable.
+/// * `S`: The `BuildHasher` used throughout, from the element-to-integer mapping to the
+///   final coverage check. Defaults to `RandomState`; swap in a faster hasher for large inputs.
 ///
 /// # Returns
 ///
-/// A `HashMap` containing the sets that form the cover.
+/// A `Vec` containing the keys of the sets that form the cover, in selection order.
 ///
 /// # Panics
 ///
 /// Panics if the input sets do not collectively cover all of their unique elements.
-pub fn greedy_set_cover_1<K, T>(sets: &HashMap<K, Vec<T>>) -> HashSet<K>
+pub fn greedy_set_cover_1<K, T, S>(sets: &IndexMap<K, Vec<T>, S>) -> Vec<K>
 where
     K: Clone + Hash + Eq + std::fmt::Debug,
     T: Clone + Hash + Eq + std::fmt::Debug,
+    S: BuildHasher + Default,
 {
-    // ... (preprocessing and bit_sets creation is identical)
-    let universe = make_universe(sets);
-    let mapping = map_elements_to_integers_owned(universe.into_iter());
-    let universe_size = mapping.len();
-    let mut bit_sets: HashMap<K, BitVec> = HashMap::new();
-    for (key, elements) in sets {
-        let mut bv = bitvec![0; universe_size];
-        for element in elements {
-            if let Some(&id) = mapping.get(element) {
-                bv.set(id, true);
+    let (bit_sets, _universe_size) = build_bit_sets(sets);
+    let items: Vec<BitVecSetItem<K>> = bit_sets
+        .into_iter()
+        .map(|(key, bits)| BitVecSetItem { key, bits })
+        .collect();
+
+    let cover: Vec<K> = greedy_cover(items, sets.len());
+
+    let covered_universe: HashSet<T, S> = cover
+        .iter()
+        .flat_map(|key| sets.get(key).expect("cover only contains keys from sets").iter().cloned())
+        .collect();
+    if covered_universe.len() != make_universe(sets).len() {
+        panic!("Error: Could not cover all elements.");
+    }
+
+    cover
+}
+
+/// A set's cached gain in the lazy-greedy max-heap, together with enough bookkeeping
+/// to know whether that gain is still fresh for the current round.
+///
+/// `index` is the set's position in the `bit_sets` `IndexMap`, used to look the set
+/// back up without needing `K: Ord` or re-hashing the key. Ties in `gain` are broken
+/// in favor of the smaller `index` so the lazy variant agrees with
+/// [`greedy_set_cover_1`]'s first-inserted-wins tie-break.
+struct HeapEntry {
+    gain: usize,
+    index: usize,
+    last_updated_round: usize,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.gain == other.gain && self.index == other.index
+    }
+}
+
+impl Eq for HeapEntry {}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.gain
+            .cmp(&other.gain)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Lazy ("accelerated") variant of [`greedy_set_cover_1`] that exploits the
+/// submodularity of set cover: a set's marginal gain over the remaining uncovered
+/// elements only ever shrinks as rounds progress, never grows. Rather than
+/// re-intersecting every set with `uncovered_elements` each round, sets are kept in a
+/// max-heap keyed by their most recently computed gain. Each round pops the set with
+/// the highest cached gain and recomputes its *current* gain; because gains are
+/// monotonically non-increasing, a recomputed entry that still ranks at or above
+/// whatever is now on top of the heap (by gain, then by smaller index) is guaranteed to
+/// be this round's best choice.
+/// Otherwise the refreshed gain is pushed back and the next candidate is tried. A
+/// per-entry `last_updated_round` marker prevents recomputing the same set twice
+/// within a round. This selects exactly the same sets as `greedy_set_cover_1`, but
+/// touches far fewer sets per round on sparse, many-set inputs.
+///
+/// # Arguments
+///
+/// * `sets`: An `IndexMap` where keys are the identifiers of the sets and values are vectors
+///   of the elements in each set.
+///
+/// # Type Parameters
+///
+/// * `K`: The type of the set identifiers (keys in the IndexMap). Must be cloneable, hashable,
+///   and equatable.
+/// * `T`: The type of the elements within the sets. Must be cloneable, hashable, and equatable.
+/// * `S`: The `BuildHasher` used by `sets` and the element-to-integer mapping.
+///
+/// # Returns
+///
+/// A `Vec` containing the keys of the sets that form the cover, in selection order.
+///
+/// # Panics
+///
+/// Panics if the input sets do not collectively cover all of their unique elements.
+pub fn greedy_set_cover_lazy<K, T, S>(sets: &IndexMap<K, Vec<T>, S>) -> Vec<K>
+where
+    K: Clone + Hash + Eq + std::fmt::Debug,
+    T: Clone + Hash + Eq + std::fmt::Debug,
+    S: BuildHasher + Default,
+{
+    let (bit_sets, universe_size) = build_bit_sets(sets);
+
+    let mut uncovered_elements = bitvec![1; universe_size];
+    let mut cover: Vec<K> = Vec::new();
+
+    let mut heap: BinaryHeap<HeapEntry> = bit_sets
+        .values()
+        .enumerate()
+        .map(|(index, bit_set)| HeapEntry {
+            gain: bit_set.count_ones(),
+            index,
+            last_updated_round: 0,
+        })
+        .collect();
+
+    let mut round = 0usize;
+
+    while uncovered_elements.any() {
+        round += 1;
+
+        let Some(mut current) = heap.pop() else {
+            break;
+        };
+
+        while current.last_updated_round != round {
+            let bit_set = bit_sets.get_index(current.index).unwrap().1;
+            let mut intersection = bit_set.clone();
+            intersection &= &uncovered_elements;
+            let gain = intersection.count_ones();
+
+            if gain == 0 {
+                let Some(next) = heap.pop() else {
+                    current.gain = 0;
+                    break;
+                };
+                current = next;
+                continue;
             }
+
+            current.gain = gain;
+            current.last_updated_round = round;
+            heap.push(current);
+            current = heap.pop().expect("the entry just pushed is still in the heap");
         }
-        bit_sets.insert(key.clone(), bv);
+
+        if current.gain == 0 {
+            break;
+        }
+
+        let (key, bit_set) = bit_sets.get_index(current.index).unwrap();
+        let mut intersection = bit_set.clone();
+        intersection &= &uncovered_elements;
+        uncovered_elements &= &!intersection;
+        cover.push(key.clone());
+    }
+
+    if uncovered_elements.any() {
+        panic!("Error: Could not cover all elements.");
+    }
+
+    cover
+}
+
+/// Solves the budget-limited maximum-coverage problem: selects at most `k` sets that
+/// together cover as many distinct elements of the universe as possible.
+///
+/// Unlike [`greedy_set_cover_1`], this does not require the universe to be fully
+/// covered. It is a thin wrapper over [`greedy_cover`]: each set is first interned
+/// into a `BitVec` over the shared universe (the same representation
+/// `greedy_set_cover_1` uses) and adapted to [`Coverable`] via `BitVecSetItem`, so
+/// the `k`-round budget and the stop-when-nothing-new-is-covered rule are the
+/// generic engine's, not a second hand-rolled copy of them, while rounds still run
+/// as bitset intersections rather than `Vec` retain/contains scans. `sets` is
+/// iterated in insertion order, so ties still go to the first-inserted key as in
+/// [`greedy_set_cover_1`].
+///
+/// # Arguments
+///
+/// * `sets`: An `IndexMap` where keys are the identifiers of the sets and values are vectors
+///   of the elements in each set.
+/// * `k`: The maximum number of sets to select.
+///
+/// # Type Parameters
+///
+/// * `K`: The type of the set identifiers (keys in the IndexMap). Must be cloneable, hashable,
+///   and equatable.
+/// * `T`: The type of the elements within the sets. Must be cloneable, hashable, and equatable.
+/// * `S`: The `BuildHasher` used throughout, from the element-to-integer mapping to the
+///   set of covered elements that is counted.
+///
+/// # Returns
+///
+/// A tuple of the chosen set keys, in selection order, and the number of distinct
+/// elements they cover.
+pub fn greedy_max_cover<K, T, S>(sets: &IndexMap<K, Vec<T>, S>, k: usize) -> (Vec<K>, usize)
+where
+    K: Clone + Hash + Eq + std::fmt::Debug,
+    T: Clone + Hash + Eq + std::fmt::Debug,
+    S: BuildHasher + Default,
+{
+    let (bit_sets, _universe_size) = build_bit_sets(sets);
+    let items: Vec<BitVecSetItem<K>> = bit_sets
+        .into_iter()
+        .map(|(key, bits)| BitVecSetItem { key, bits })
+        .collect();
+
+    let cover: Vec<K> = greedy_cover(items, k);
+
+    let mut covered: HashSet<T, S> = HashSet::default();
+    for key in &cover {
+        covered.extend(sets.get(key).expect("cover only contains keys from sets").iter().cloned());
+    }
+    let total_covered = covered.len();
+
+    (cover, total_covered)
+}
+
+/// Adapts a set interned as a `BitVec` over the shared universe to [`Coverable`],
+/// so [`greedy_max_cover`] can be a thin wrapper over [`greedy_cover`] while still
+/// shrinking candidates via bitset intersection each round instead of a `Vec`
+/// retain/contains scan.
+struct BitVecSetItem<K> {
+    key: K,
+    bits: BitVec,
+}
+
+impl<K: Clone> Coverable for BitVecSetItem<K> {
+    type Object = K;
+    type Set = BitVec;
+
+    fn covering_set(&self) -> &BitVec {
+        &self.bits
+    }
+
+    fn object(&self) -> K {
+        self.key.clone()
     }
 
+    fn prepare_removal(chosen: &BitVec) -> BitVec {
+        // Negated once per round here, instead of once per surviving item in
+        // `update_covering_set`.
+        !chosen.clone()
+    }
+
+    fn update_covering_set(&mut self, removal: &BitVec) {
+        self.bits &= removal;
+    }
+
+    fn score(&self) -> usize {
+        self.bits.count_ones()
+    }
+}
+
+/// Finds an approximate solution to the *weighted* set cover problem, where each set
+/// carries a cost and the goal is to minimize the total cost of the selected cover
+/// rather than the number of sets selected.
+///
+/// The greedy rule changes from "most new elements" to "best cost-effectiveness":
+/// each round picks the set minimizing `cost(S) / |S ∩ uncovered|`, which gives the
+/// standard `H(n)`-approximation for weighted set cover. Sets with no cost entry in
+/// `costs` are skipped, as are rounds where a set would cover nothing new.
+///
+/// # Arguments
+///
+/// * `sets`: An `IndexMap` where keys are the identifiers of the sets and values are vectors
+///   of the elements in each set.
+/// * `costs`: A `HashMap` from set key to its cost.
+///
+/// # Type Parameters
+///
+/// * `K`: The type of the set identifiers (keys in the IndexMap). Must be cloneable, hashable,
+///   and equatable.
+/// * `T`: The type of the elements within the sets. Must be cloneable, hashable, and equatable.
+/// * `S`: The `BuildHasher` shared by `sets`, `costs`, and the element-to-integer mapping.
+///
+/// # Returns
+///
+/// A tuple of the chosen set keys, in selection order, and the total accumulated
+/// cost.
+///
+/// # Panics
+///
+/// Panics if the input sets do not collectively cover all of their unique elements.
+pub fn greedy_weighted_set_cover<K, T, S>(
+    sets: &IndexMap<K, Vec<T>, S>,
+    costs: &HashMap<K, f64, S>,
+) -> (Vec<K>, f64)
+where
+    K: Clone + Hash + Eq + std::fmt::Debug,
+    T: Clone + Hash + Eq + std::fmt::Debug,
+    S: BuildHasher + Default,
+{
+    let (bit_sets, universe_size) = build_bit_sets(sets);
+
     let mut uncovered_elements = bitvec![1; universe_size];
-    let mut cover: HashSet<K> = HashSet::new();
+    let mut cover: Vec<K> = Vec::new();
+    let mut total_cost = 0.0;
 
-    // OPTIMIZATION: Create a reusable buffer for intersection calculations.
-    // We allocate it once here, outside all loops that use it.
     let mut intersection_buffer = BitVec::with_capacity(universe_size);
 
     for _ in 0..sets.len() {
@@ -251,23 +594,26 @@ where
         }
 
         let mut best_set_key: Option<K> = None;
-        let mut best_set_covered_count = 0;
+        let mut best_ratio = f64::INFINITY;
         let mut best_intersection: Option<BitVec> = None;
 
         for (key, bit_set) in &bit_sets {
-            // OPTIMIZATION: Instead of `clone`, use `clone_from` to reuse the
-            // buffer's allocation. This turns a potentially slow allocation
-            // into a much faster memory copy.
+            let Some(&cost) = costs.get(key) else {
+                continue;
+            };
+
             intersection_buffer.clone_from(bit_set);
             intersection_buffer &= &uncovered_elements;
 
             let covered_count = intersection_buffer.count_ones();
+            if covered_count == 0 {
+                continue;
+            }
 
-            if covered_count > best_set_covered_count {
+            let ratio = cost / covered_count as f64;
+            if ratio < best_ratio {
                 best_set_key = Some(key.clone());
-                best_set_covered_count = covered_count;
-                // We still need to clone here to save the result for later,
-                // as the buffer will be overwritten in the next iteration.
+                best_ratio = ratio;
                 best_intersection = Some(intersection_buffer.clone());
             }
         }
@@ -276,7 +622,8 @@ where
             if let Some(elements_to_remove) = best_intersection {
                 uncovered_elements &= &!elements_to_remove;
             }
-            cover.insert(key);
+            total_cost += costs[&key];
+            cover.push(key);
         } else if uncovered_elements.any() {
             panic!("Error: Unable to find a set to cover remaining elements.");
         }
@@ -286,12 +633,101 @@ where
         panic!("Error: Could not cover all elements.");
     }
 
-    cover
+    (cover, total_cost)
+}
+
+/// Decouples the greedy covering algorithm from any particular set representation.
+///
+/// `greedy_max_cover` is one such caller: it interns each `IndexMap` entry into a
+/// `BitVecSetItem` rather than hand-rolling its own budgeted `BitVec` intersection
+/// loop, so new callers (roaring bitmaps, interval sets, ...) just need their own
+/// `Coverable` impl to reuse the same loop.
+pub trait Coverable {
+    /// The value returned for this item when it is selected.
+    type Object;
+    /// The representation of the elements this item still covers.
+    type Set: Clone;
+
+    /// The elements this item currently covers.
+    fn covering_set(&self) -> &Self::Set;
+
+    /// The value to return if this item is selected.
+    fn object(&self) -> Self::Object;
+
+    /// Precomputes, once per round, whatever `update_covering_set` needs to remove
+    /// this round's `chosen` set. Defaults to cloning `chosen` unchanged, matching
+    /// implementations (like `VecSetItem`) whose `update_covering_set` wants the raw
+    /// chosen set. Override this when `update_covering_set` would otherwise have to
+    /// redo the same transformation of `chosen` for every surviving item (e.g.
+    /// negating a `BitVec` once here instead of once per item).
+    fn prepare_removal(chosen: &Self::Set) -> Self::Set {
+        chosen.clone()
+    }
+
+    /// Removes whatever `chosen` just covered from this item's covering set, given
+    /// this round's precomputed [`Self::prepare_removal`] output.
+    fn update_covering_set(&mut self, chosen: &Self::Set);
+
+    /// How many elements this item currently covers; the greedy loop always picks
+    /// the surviving item with the highest score.
+    fn score(&self) -> usize;
+}
+
+/// Runs the greedy covering loop against any [`Coverable`] item type, selecting up
+/// to `limit` items by highest `score()` and shrinking the rest via
+/// `update_covering_set` after each pick.
+///
+/// Items with a score of zero are never selected. An availability flag is kept per
+/// item so selected or exhausted items are skipped without removing them from the
+/// working set.
+///
+/// # Type Parameters
+///
+/// * `C`: The item type, which must implement [`Coverable`].
+///
+/// # Returns
+///
+/// The `Object` of each selected item, in selection order.
+pub fn greedy_cover<C: Coverable>(items: impl IntoIterator<Item = C>, limit: usize) -> Vec<C::Object> {
+    let mut items: Vec<(C, bool)> = items.into_iter().map(|item| (item, true)).collect();
+    let mut selected = Vec::new();
+
+    for _ in 0..limit.min(items.len()) {
+        let best_index = items
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, available))| *available)
+            .map(|(index, (item, _))| (index, item.score()))
+            .filter(|(_, score)| *score > 0)
+            // `max_by_key` returns the *last* maximal element on a tie; reverse first
+            // so ties resolve to the earliest (smallest-index) item instead.
+            .rev()
+            .max_by_key(|(_, score)| *score)
+            .map(|(index, _)| index);
+
+        let Some(best_index) = best_index else {
+            break;
+        };
+
+        let chosen_set = items[best_index].0.covering_set().clone();
+        let removal = C::prepare_removal(&chosen_set);
+        selected.push(items[best_index].0.object());
+        items[best_index].1 = false;
+
+        for (item, available) in items.iter_mut() {
+            if *available {
+                item.update_covering_set(&removal);
+            }
+        }
+    }
+
+    selected
 }
 
 #[cfg(test)]
 mod tests {
     use super::*; // Imports greedy_set_cover from the parent module
+    use indexmap::IndexMap;
     use std::collections::{HashMap, HashSet};
 
     type Set = HashSet<i32>;
@@ -302,7 +738,7 @@ mod tests {
 
     #[test]
     fn test_basic_case() {
-        let mut sets = HashMap::new();
+        let mut sets = IndexMap::new();
         sets.insert("A".to_string(), vec![1, 2, 3]);
         sets.insert("B".to_string(), vec![1, 2]);
         sets.insert("C".to_string(), vec![2]);
@@ -310,7 +746,7 @@ mod tests {
         let set_cover = greedy_set_cover_1(&sets);
         let universe = make_universe(&sets);
 
-        let covered_sets: HashMap<String, Vec<i32>> = set_cover
+        let covered_sets: IndexMap<String, Vec<i32>> = set_cover
             .iter()
             .map(|key| (key.clone(), sets.get(key).unwrap().clone()))
             .collect();
@@ -563,7 +999,7 @@ mod tests {
             "apple".to_string(),
         ];
 
-        let forward_map = map_elements_to_integers(data.iter());
+        let forward_map: HashMap<&String, usize> = map_elements_to_integers(data.iter());
         assert_eq!(forward_map.len(), 3);
         assert!(forward_map.contains_key(&"apple".to_string()));
         assert!(forward_map.contains_key(&"banana".to_string()));
@@ -572,4 +1008,121 @@ mod tests {
         let values: HashSet<usize> = forward_map.values().cloned().collect();
         assert_eq!(values, HashSet::from([0, 1, 2]));
     }
+
+    #[test]
+    fn test_greedy_max_cover_respects_budget() {
+        let mut sets = IndexMap::new();
+        sets.insert("A".to_string(), vec![1, 2, 3, 4]);
+        sets.insert("B".to_string(), vec![4, 5, 6]);
+        sets.insert("C".to_string(), vec![7, 8]);
+
+        let (cover, covered_count) = greedy_max_cover(&sets, 1);
+        assert_eq!(cover, vec!["A".to_string()]);
+        assert_eq!(covered_count, 4);
+    }
+
+    #[test]
+    fn test_greedy_max_cover_stops_when_nothing_new() {
+        let mut sets = IndexMap::new();
+        sets.insert(1, vec![1, 2]);
+        sets.insert(2, vec![1, 2]);
+
+        let (cover, covered_count) = greedy_max_cover(&sets, 5);
+        assert_eq!(cover.len(), 1);
+        assert_eq!(covered_count, 2);
+    }
+
+    #[test]
+    fn test_greedy_weighted_set_cover_prefers_cheap_coverage() {
+        let mut sets = IndexMap::new();
+        sets.insert("expensive".to_string(), vec![1, 2, 3]);
+        sets.insert("cheap".to_string(), vec![1, 2, 3]);
+
+        let mut costs = HashMap::new();
+        costs.insert("expensive".to_string(), 10.0);
+        costs.insert("cheap".to_string(), 1.0);
+
+        let (cover, total_cost) = greedy_weighted_set_cover(&sets, &costs);
+        assert_eq!(cover, vec!["cheap".to_string()]);
+        assert_eq!(total_cost, 1.0);
+    }
+
+    struct BitSetItem {
+        key: &'static str,
+        bits: BitVec,
+    }
+
+    impl Coverable for BitSetItem {
+        type Object = &'static str;
+        type Set = BitVec;
+
+        fn covering_set(&self) -> &BitVec {
+            &self.bits
+        }
+
+        fn object(&self) -> &'static str {
+            self.key
+        }
+
+        fn update_covering_set(&mut self, chosen: &BitVec) {
+            self.bits &= !chosen.clone();
+        }
+
+        fn score(&self) -> usize {
+            self.bits.count_ones()
+        }
+    }
+
+    #[test]
+    fn test_greedy_cover_with_custom_coverable() {
+        let items = vec![
+            BitSetItem {
+                key: "A",
+                bits: bitvec![1, 1, 1, 0, 0],
+            },
+            BitSetItem {
+                key: "B",
+                bits: bitvec![0, 0, 1, 1, 1],
+            },
+        ];
+
+        let chosen = greedy_cover(items, 2);
+        assert_eq!(chosen, vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_greedy_set_cover_1_breaks_ties_by_insertion_order() {
+        // "first" and "second" cover the exact same elements, so whichever was
+        // inserted first into the IndexMap must be the one selected.
+        let mut sets = IndexMap::new();
+        sets.insert("first".to_string(), vec![1, 2, 3]);
+        sets.insert("second".to_string(), vec![1, 2, 3]);
+
+        let cover = greedy_set_cover_1(&sets);
+        assert_eq!(cover, vec!["first".to_string()]);
+    }
+
+    #[test]
+    fn test_greedy_set_cover_lazy_matches_greedy_set_cover_1() {
+        let mut sets = IndexMap::new();
+        sets.insert("A".to_string(), vec![1, 2, 3, 4, 5, 6]);
+        sets.insert("B".to_string(), vec![1, 2, 7]);
+        sets.insert("C".to_string(), vec![3, 4, 8]);
+        sets.insert("D".to_string(), vec![5, 6, 9]);
+        sets.insert("E".to_string(), vec![7, 8, 9, 10]);
+
+        assert_eq!(greedy_set_cover_lazy(&sets), greedy_set_cover_1(&sets));
+    }
+
+    #[test]
+    fn test_greedy_set_cover_lazy_breaks_ties_by_insertion_order() {
+        // Same tie-break scenario as greedy_set_cover_1: "first" must win since it
+        // was inserted first and covers the same elements as "second".
+        let mut sets = IndexMap::new();
+        sets.insert("first".to_string(), vec![1, 2, 3]);
+        sets.insert("second".to_string(), vec![1, 2, 3]);
+
+        let cover = greedy_set_cover_lazy(&sets);
+        assert_eq!(cover, vec!["first".to_string()]);
+    }
 }
@@ -1,11 +1,11 @@
 mod greedy_set_cover;
 
 use crate::greedy_set_cover::greedy_set_cover_0;
-use std::collections::HashMap;
+use indexmap::IndexMap;
 
 fn main() {
     // Example with string slices as set identifiers and integers as elements.
-    let mut sets_to_cover: HashMap<&str, Vec<i32>> = HashMap::new();
+    let mut sets_to_cover: IndexMap<&str, Vec<i32>> = IndexMap::new();
     sets_to_cover.insert("S1", vec![1, 2, 3, 6]);
     sets_to_cover.insert("S2", vec![2, 4]);
     sets_to_cover.insert("S3", vec![3, 5]);
@@ -16,7 +16,7 @@ fn main() {
     println!("Selected sets to cover all elements: {:?}", cover);
 
     // Example with integer keys and character elements.
-    let mut sets_to_cover_2: HashMap<i32, Vec<char>> = HashMap::new();
+    let mut sets_to_cover_2: IndexMap<i32, Vec<char>> = IndexMap::new();
     sets_to_cover_2.insert(1, vec!['a', 'b']);
     sets_to_cover_2.insert(2, vec!['b', 'c', 'd']);
     sets_to_cover_2.insert(3, vec!['d', 'e']);